@@ -0,0 +1,275 @@
+use rosu_map::Beatmap;
+
+use crate::analyze::{Jump, JumpAnalysis, Stream, StreamAnalysis};
+
+/// Aggregates `JumpAnalysis`/`StreamAnalysis` results across many beatmaps into
+/// summary statistics, e.g. for a whole mapper's catalog or a tournament mappool.
+pub struct Aggregate {
+    jump_analyses: Vec<JumpAnalysis>,
+    stream_analyses: Vec<StreamAnalysis>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p25: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+/// A coarse histogram of `overall_confidence` values, bucketed into tenths
+/// (`buckets[0]` is `[0.0, 0.1)`, ..., `buckets[9]` is `[0.9, 1.0]`).
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ConfidenceHistogram {
+    pub buckets: [usize; 10],
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct AggregateReport {
+    /// Number of beatmaps the report was computed over. `jump_density`,
+    /// `jump_bpm_consistency`, `jump_overall_confidence`, `stream_bpm_consistency`,
+    /// `max_stream_length` and `stream_overall_confidence` are each reduced over
+    /// exactly this many values.
+    pub sample_count: usize,
+
+    pub jump_density: Stats,
+    pub jump_bpm_consistency: Stats,
+    pub jump_overall_confidence: Stats,
+
+    pub stream_bpm_consistency: Stats,
+    pub max_stream_length: Stats,
+    pub stream_overall_confidence: Stats,
+
+    /// Histogram of every `overall_confidence` value from both jump and stream
+    /// analyses, i.e. `2 * sample_count` values in total.
+    pub confidence_histogram: ConfidenceHistogram,
+}
+
+impl Aggregate {
+    /// Creates a new aggregate analyzer by running `Jump` and `Stream` analysis
+    /// over every beatmap in `maps`.
+    pub fn new(maps: Vec<Beatmap>) -> Self {
+        let jump_analyses = maps
+            .iter()
+            .cloned()
+            .map(|map| Jump::new(map).analyze())
+            .collect();
+        let stream_analyses = maps
+            .into_iter()
+            .map(|map| Stream::new(map).analyze())
+            .collect();
+
+        Self {
+            jump_analyses,
+            stream_analyses,
+        }
+    }
+
+    /// Creates a new aggregate analyzer from already-computed analyses, for
+    /// callers that analyzed their beatmaps ahead of time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `jump_analyses` and `stream_analyses` don't have the same length;
+    /// they must contain one analysis per beatmap so that `sample_count` and the
+    /// per-field statistics describe a single, consistent population.
+    pub fn from_analyses(
+        jump_analyses: Vec<JumpAnalysis>,
+        stream_analyses: Vec<StreamAnalysis>,
+    ) -> Self {
+        assert_eq!(
+            jump_analyses.len(),
+            stream_analyses.len(),
+            "Aggregate requires one JumpAnalysis and one StreamAnalysis per beatmap"
+        );
+
+        Self {
+            jump_analyses,
+            stream_analyses,
+        }
+    }
+
+    /// Reduces the collected analyses into an `AggregateReport`.
+    pub fn summarize(&self) -> AggregateReport {
+        let jump_density: Vec<f64> = self.jump_analyses.iter().map(|a| a.jump_density).collect();
+        let jump_bpm_consistency: Vec<f64> = self
+            .jump_analyses
+            .iter()
+            .map(|a| a.bpm_consistency)
+            .collect();
+        let jump_overall_confidence: Vec<f64> = self
+            .jump_analyses
+            .iter()
+            .map(|a| a.overall_confidence)
+            .collect();
+
+        let stream_bpm_consistency: Vec<f64> = self
+            .stream_analyses
+            .iter()
+            .map(|a| a.bpm_consistency)
+            .collect();
+        let max_stream_length: Vec<f64> = self
+            .stream_analyses
+            .iter()
+            .map(|a| a.max_stream_length as f64)
+            .collect();
+        let stream_overall_confidence: Vec<f64> = self
+            .stream_analyses
+            .iter()
+            .map(|a| a.overall_confidence)
+            .collect();
+
+        let all_confidence: Vec<f64> = jump_overall_confidence
+            .iter()
+            .copied()
+            .chain(stream_overall_confidence.iter().copied())
+            .collect();
+
+        AggregateReport {
+            sample_count: self.jump_analyses.len(),
+            jump_density: stats(&jump_density),
+            jump_bpm_consistency: stats(&jump_bpm_consistency),
+            jump_overall_confidence: stats(&jump_overall_confidence),
+            stream_bpm_consistency: stats(&stream_bpm_consistency),
+            max_stream_length: stats(&max_stream_length),
+            stream_overall_confidence: stats(&stream_overall_confidence),
+            confidence_histogram: confidence_histogram(&all_confidence),
+        }
+    }
+}
+
+/// Computes mean, median, standard deviation, min/max and p25/p75/p95 for `values`.
+fn stats(values: &[f64]) -> Stats {
+    if values.is_empty() {
+        return Stats {
+            mean: 0.0,
+            median: 0.0,
+            std_dev: 0.0,
+            min: 0.0,
+            max: 0.0,
+            p25: 0.0,
+            p75: 0.0,
+            p95: 0.0,
+        };
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+    let variance =
+        sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / sorted.len() as f64;
+
+    Stats {
+        mean,
+        median: percentile(&sorted, 0.50),
+        std_dev: variance.sqrt(),
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        p25: percentile(&sorted, 0.25),
+        p75: percentile(&sorted, 0.75),
+        p95: percentile(&sorted, 0.95),
+    }
+}
+
+/// Linear-interpolated percentile of an already-sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+fn confidence_histogram(values: &[f64]) -> ConfidenceHistogram {
+    let mut buckets = [0usize; 10];
+
+    for &value in values {
+        let bucket = ((value.clamp(0.0, 1.0) * 10.0) as usize).min(9);
+        buckets[bucket] += 1;
+    }
+
+    ConfidenceHistogram { buckets }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_empty_is_zeroed() {
+        let result = stats(&[]);
+
+        assert_eq!(result.mean, 0.0);
+        assert_eq!(result.min, 0.0);
+        assert_eq!(result.max, 0.0);
+    }
+
+    #[test]
+    fn stats_computes_mean_median_and_bounds() {
+        let result = stats(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        assert_eq!(result.mean, 3.0);
+        assert_eq!(result.median, 3.0);
+        assert_eq!(result.min, 1.0);
+        assert_eq!(result.max, 5.0);
+        assert!((result.std_dev - 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn percentile_interpolates_between_ranks() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 1.0), 4.0);
+        assert!((percentile(&sorted, 0.5) - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn percentile_of_single_value() {
+        assert_eq!(percentile(&[7.0], 0.95), 7.0);
+    }
+
+    #[test]
+    fn confidence_histogram_buckets_by_tenths() {
+        let histogram = confidence_histogram(&[0.0, 0.05, 0.95, 1.0]);
+
+        assert_eq!(histogram.buckets[0], 2);
+        assert_eq!(histogram.buckets[9], 2);
+        assert_eq!(histogram.buckets.iter().sum::<usize>(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_analyses_rejects_mismatched_lengths() {
+        let jump = JumpAnalysis {
+            overall_confidence: 0.0,
+            total_jump_count: 0,
+            max_jump_length: 0,
+            long_jumps: 0,
+            medium_jumps: 0,
+            short_jumps: 0,
+            jump_density: 0.0,
+            bpm_consistency: 0.0,
+            snap_histogram: crate::utils::SnapHistogram::default(),
+        };
+
+        Aggregate::from_analyses(vec![jump], vec![]);
+    }
+}
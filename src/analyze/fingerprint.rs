@@ -0,0 +1,103 @@
+use rosu_map::Beatmap;
+
+use crate::analyze::{Jump, Stream};
+use crate::utils::bpm as compute_bpm;
+
+/// Length of a beatmap fingerprint vector.
+pub const FINGERPRINT_LEN: usize = 9;
+
+/// A fixed-length, normalized feature vector describing how a beatmap plays, built
+/// from its BPM, object count, and `JumpAnalysis`/`StreamAnalysis` fields. Every
+/// component is normalized into `[0.0, 1.0]` so components are comparable.
+pub type Fingerprint = [f64; FINGERPRINT_LEN];
+
+/// Computes a `Fingerprint` for `map` by running `Jump` and `Stream` analysis and
+/// normalizing the resulting fields, plus BPM and object count, into `[0.0, 1.0]`.
+pub fn fingerprint(map: &Beatmap) -> Fingerprint {
+    let mut map = map.clone();
+    let bpm = compute_bpm(
+        map.hit_objects.last_mut(),
+        &map.control_points.timing_points,
+    );
+
+    let jump_analysis = Jump::new(map.clone()).analyze();
+    let stream_analysis = Stream::new(map.clone()).analyze();
+
+    [
+        normalize(bpm, 60.0, 300.0),
+        normalize(map.hit_objects.len() as f64, 0.0, 4000.0),
+        jump_analysis.jump_density.clamp(0.0, 1.0),
+        jump_analysis.bpm_consistency.clamp(0.0, 1.0),
+        normalize(jump_analysis.max_jump_length as f64, 0.0, 40.0),
+        jump_analysis.overall_confidence.clamp(0.0, 1.0),
+        stream_analysis.stream_density.clamp(0.0, 1.0),
+        normalize(stream_analysis.max_stream_length as f64, 0.0, 200.0),
+        stream_analysis.overall_confidence.clamp(0.0, 1.0),
+    ]
+}
+
+/// Normalizes `value` from `[min, max]` into `[0.0, 1.0]`, clamping out-of-range values.
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+/// Euclidean distance between two fingerprints; smaller means "plays more alike".
+pub fn distance(a: &Fingerprint, b: &Fingerprint) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Finds the `k` maps in `candidates` whose fingerprint is closest to `query`,
+/// returning `(index into candidates, distance)` pairs sorted nearest-first.
+pub fn most_similar(query: &Fingerprint, candidates: &[Beatmap], k: usize) -> Vec<(usize, f64)> {
+    let mut distances: Vec<(usize, f64)> = candidates
+        .iter()
+        .enumerate()
+        .map(|(index, map)| (index, distance(query, &fingerprint(map))))
+        .collect();
+
+    distances.sort_by(|a, b| a.1.total_cmp(&b.1));
+    distances.truncate(k);
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_maps_range_to_unit_interval() {
+        assert_eq!(normalize(60.0, 60.0, 300.0), 0.0);
+        assert_eq!(normalize(300.0, 60.0, 300.0), 1.0);
+        assert!((normalize(180.0, 60.0, 300.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalize_clamps_out_of_range_values() {
+        assert_eq!(normalize(0.0, 60.0, 300.0), 0.0);
+        assert_eq!(normalize(1000.0, 60.0, 300.0), 1.0);
+    }
+
+    #[test]
+    fn distance_of_identical_fingerprints_is_zero() {
+        let fp: Fingerprint = [0.5; FINGERPRINT_LEN];
+
+        assert_eq!(distance(&fp, &fp), 0.0);
+    }
+
+    #[test]
+    fn distance_matches_euclidean_norm() {
+        let mut a: Fingerprint = [0.0; FINGERPRINT_LEN];
+        let mut b: Fingerprint = [0.0; FINGERPRINT_LEN];
+        a[0] = 0.0;
+        b[0] = 3.0;
+        a[1] = 0.0;
+        b[1] = 4.0;
+
+        assert_eq!(distance(&a, &b), 5.0);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::utils::{bpm, calculate_distance};
+use crate::utils::{
+    active_bpm, bpm, bpm_segments, calculate_distance, classify_snap, classify_snaps,
+    snap_histogram, BpmSegment, Snap, SnapHistogram,
+};
 use rosu_map::{section::hit_objects::HitObject, Beatmap};
 use std::collections::VecDeque;
 
@@ -19,6 +22,8 @@ pub struct JumpAnalysis {
 
     pub jump_density: f64,
     pub bpm_consistency: f64,
+
+    pub snap_histogram: SnapHistogram,
 }
 
 impl Jump {
@@ -49,11 +54,19 @@ impl Jump {
             &self.map.control_points.timing_points,
         );
         let beat_length = 60.0 / bpm * 1000.0;
-        let expected_jump_interval = beat_length / 2.0; // 1/2ths
+        let expected_jump_interval = beat_length / 2.0; // 1/2ths, used for confidence scoring
+
+        let segments = bpm_segments(
+            self.map.hit_objects.last_mut(),
+            &self.map.control_points.timing_points,
+        );
         let hit_objects = &self.map.hit_objects;
 
         let (consecutive_notes, bpm_variations) =
-            self.calculate_consecutive_notes(hit_objects, expected_jump_interval);
+            self.calculate_consecutive_notes(hit_objects, &segments, bpm);
+
+        let note_times: Vec<f64> = hit_objects.iter().map(|o| o.start_time).collect();
+        let snap_histogram = snap_histogram(&classify_snaps(&note_times, beat_length));
 
         // Calculate jumps' lengths
         let short_jumps_amount = consecutive_notes
@@ -115,18 +128,19 @@ impl Jump {
             overall_confidence,
             jump_density,
             bpm_consistency,
+            snap_histogram,
         }
     }
 
     fn calculate_consecutive_notes(
         &self,
         hit_objects: &[HitObject],
-        expected_interval: f64,
+        segments: &[BpmSegment],
+        fallback_bpm: f64,
     ) -> (Vec<usize>, Vec<f64>) {
         let mut jumps_lengths = Vec::new();
         let mut curr_jump = VecDeque::new();
         let mut bpm_variations = Vec::new();
-        let tolerance = 0.10; // 10% tolerance
         let distance_threshold = 120.0_f32;
 
         for pair in hit_objects.windows(2) {
@@ -135,10 +149,10 @@ impl Jump {
 
             let time_diff = obj2.start_time - obj1.start_time;
             let distance = calculate_distance(obj1, obj2);
+            let beat_length = 60_000.0 / active_bpm(segments, obj1.start_time, fallback_bpm);
 
-            // Check if the pair is between expected interval.
-            if (time_diff - expected_interval).abs() / expected_interval <= tolerance
-                && distance >= distance_threshold
+            // Check if the pair snaps to a 1/2th, i.e. jump territory.
+            if classify_snap(time_diff, beat_length) == Snap::Half && distance >= distance_threshold
             {
                 curr_jump.push_back(time_diff);
                 if curr_jump.len() > 1 {
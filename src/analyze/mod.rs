@@ -0,0 +1,9 @@
+mod aggregate;
+mod fingerprint;
+mod jump;
+mod stream;
+
+pub use aggregate::{Aggregate, AggregateReport, ConfidenceHistogram, Stats};
+pub use fingerprint::{distance, fingerprint, most_similar, Fingerprint, FINGERPRINT_LEN};
+pub use jump::{Jump, JumpAnalysis};
+pub use stream::{Stream, StreamAnalysis};
@@ -1,4 +1,7 @@
-use crate::utils::bpm;
+use crate::utils::{
+    active_bpm, bpm, bpm_segments, classify_snap, classify_snaps, snap_histogram, BpmSegment,
+    Snap, SnapHistogram,
+};
 use rosu_map::{section::hit_objects::HitObject, Beatmap};
 use std::collections::VecDeque;
 
@@ -17,6 +20,8 @@ pub struct StreamAnalysis {
     pub max_stream_length: usize,
     pub stream_density: f64,
     pub bpm_consistency: f64,
+
+    pub snap_histogram: SnapHistogram,
 }
 
 impl Stream {
@@ -47,12 +52,19 @@ impl Stream {
             &self.map.control_points.timing_points,
         );
         let beat_length = 60.0 / bpm * 1000.0;
-        let expected_stream_interval = beat_length / 4.0; // 1/4ths
+        let expected_stream_interval = beat_length / 4.0; // 1/4ths, used for confidence scoring
 
+        let segments = bpm_segments(
+            self.map.hit_objects.last_mut(),
+            &self.map.control_points.timing_points,
+        );
         let hit_objects = &self.map.hit_objects;
 
         let (consecutive_notes, bpm_variations) =
-            self.calculate_consecutive_notes(hit_objects, expected_stream_interval);
+            self.calculate_consecutive_notes(hit_objects, &segments, bpm);
+
+        let note_times: Vec<f64> = hit_objects.iter().map(|o| o.start_time).collect();
+        let snap_histogram = snap_histogram(&classify_snaps(&note_times, beat_length));
 
         let bursts_amount = consecutive_notes
             .iter()
@@ -120,18 +132,19 @@ impl Stream {
             max_stream_length,
             stream_density,
             bpm_consistency,
+            snap_histogram,
         }
     }
 
     fn calculate_consecutive_notes(
         &self,
         hit_objects: &[HitObject],
-        expected_interval: f64,
+        segments: &[BpmSegment],
+        fallback_bpm: f64,
     ) -> (Vec<usize>, Vec<f64>) {
         let mut stream_lengths = Vec::new();
         let mut current_stream = VecDeque::new();
         let mut bpm_variations = Vec::new();
-        let tolerance = 0.10; // 10% tolerance
 
         // Look at streams in pairs
         // We do this so we can see if the note next to the curr note is a stream
@@ -141,9 +154,10 @@ impl Stream {
         // and then we look at their time differences, and if they're within our intervals, it counts as a consecutive note.
         for pair in hit_objects.windows(2) {
             let time_diff = pair[1].start_time - pair[0].start_time;
+            let beat_length = 60_000.0 / active_bpm(segments, pair[0].start_time, fallback_bpm);
 
-            // Check if the pair is between expected interval.
-            if (time_diff - expected_interval).abs() / expected_interval <= tolerance {
+            // Check if the pair snaps to a 1/4th, i.e. stream territory.
+            if classify_snap(time_diff, beat_length) == Snap::Quarter {
                 current_stream.push_back(time_diff);
                 if current_stream.len() > 1 {
                     let prev_diff = current_stream[current_stream.len() - 2];
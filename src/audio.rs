@@ -0,0 +1,331 @@
+//! Independent, audio-derived tempo estimation, for cross-checking a beatmap's
+//! `.osu` timing points against its actual audio. Gated behind the
+//! `audio-analysis` feature since it pulls in audio decoding and FFT dependencies.
+
+use std::path::Path;
+
+use rosu_map::Beatmap;
+
+/// A detected onset timestamp, in milliseconds from the start of the audio.
+pub type OnsetMs = f64;
+
+/// Errors that can occur while reading or decoding a beatmap's referenced audio file.
+#[derive(Debug)]
+pub enum AudioError {
+    Io(std::io::Error),
+    Decode(String),
+}
+
+impl std::fmt::Display for AudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioError::Io(err) => write!(f, "failed to read audio file: {err}"),
+            AudioError::Decode(msg) => write!(f, "failed to decode audio file: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AudioError {}
+
+impl From<std::io::Error> for AudioError {
+    fn from(err: std::io::Error) -> Self {
+        AudioError::Io(err)
+    }
+}
+
+/// An independent, audio-derived tempo estimate for a beatmap.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct AudioBpmEstimate {
+    pub bpm: f64,
+    pub onsets: Vec<OnsetMs>,
+}
+
+/// How an audio-derived BPM estimate compares against the beatmap's timing points.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BpmComparison {
+    pub audio_bpm: f64,
+    pub timing_point_bpm: f64,
+    pub discrepancy_percent: f64,
+    /// `true` when the discrepancy is large enough to suggest mistimed or
+    /// incorrect metadata BPM.
+    pub likely_mistimed: bool,
+}
+
+const WINDOW_SIZE: usize = 1024;
+const HOP_SIZE: usize = 512;
+const ONSET_SENSITIVITY: f64 = 1.5;
+const MIN_BPM: f64 = 60.0;
+const MAX_BPM: f64 = 300.0;
+const MISTIMED_THRESHOLD_PERCENT: f64 = 5.0;
+const INTERVAL_BUCKET_MS: f64 = 5.0;
+
+/// Reads the beatmap's referenced audio file (resolved relative to `map_dir`) and
+/// estimates its tempo independently of the `.osu` timing points, via spectral-flux
+/// onset detection.
+pub fn analyze_audio(map: &Beatmap, map_dir: &Path) -> Result<AudioBpmEstimate, AudioError> {
+    let audio_path = map_dir.join(&map.audio_file);
+    let (samples, sample_rate) = decode_audio(&audio_path)?;
+
+    let onsets = detect_onsets(&samples, sample_rate);
+    let bpm = estimate_bpm(&onsets);
+
+    Ok(AudioBpmEstimate { bpm, onsets })
+}
+
+/// Compares an audio-derived BPM estimate against the beatmap's timing-point-derived BPM.
+pub fn compare_bpm(estimate: &AudioBpmEstimate, timing_point_bpm: f64) -> BpmComparison {
+    let discrepancy_percent = if timing_point_bpm > 0.0 {
+        ((estimate.bpm - timing_point_bpm).abs() / timing_point_bpm) * 100.0
+    } else {
+        0.0
+    };
+
+    BpmComparison {
+        audio_bpm: estimate.bpm,
+        timing_point_bpm,
+        discrepancy_percent,
+        likely_mistimed: discrepancy_percent > MISTIMED_THRESHOLD_PERCENT,
+    }
+}
+
+/// Decodes `path` into mono PCM samples and its sample rate.
+///
+/// Delegates to `symphonia` so we pick up whatever container/codec the beatmap's
+/// audio file happens to be in (mp3, ogg, wav, ...).
+fn decode_audio(path: &Path) -> Result<(Vec<f32>, u32), AudioError> {
+    use symphonia::core::audio::Signal;
+    use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    let file = std::fs::File::open(path)?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            mss,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|err| AudioError::Decode(err.to_string()))?;
+
+    let mut format = probed.format;
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| AudioError::Decode("no playable audio track".into()))?;
+
+    let track_id = track.id;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| AudioError::Decode("missing sample rate".into()))?;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|err| AudioError::Decode(err.to_string()))?;
+
+    let mut samples = Vec::new();
+
+    while let Ok(packet) = format.next_packet() {
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = decoder
+            .decode(&packet)
+            .map_err(|err| AudioError::Decode(err.to_string()))?;
+
+        let spec = *decoded.spec();
+        let mut buf =
+            symphonia::core::audio::SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buf.copy_interleaved_ref(decoded);
+
+        // Downmix to mono by averaging channels.
+        let channels = spec.channels.count().max(1);
+        samples.extend(
+            buf.samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+    }
+
+    Ok((samples, sample_rate))
+}
+
+/// Spectral-flux onset detection: splits `samples` into overlapping windows, computes
+/// the FFT magnitude spectrum per window, and peak-picks where the positive spectral
+/// flux exceeds an adaptive (local mean × sensitivity) threshold.
+fn detect_onsets(samples: &[f32], sample_rate: u32) -> Vec<OnsetMs> {
+    use rustfft::{num_complex::Complex, FftPlanner};
+
+    if samples.len() < WINDOW_SIZE {
+        return Vec::new();
+    }
+
+    let mut planner = FftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(WINDOW_SIZE);
+
+    let window_count = (samples.len() - WINDOW_SIZE) / HOP_SIZE + 1;
+    let mut flux = Vec::with_capacity(window_count);
+    let mut prev_magnitudes = vec![0.0_f32; WINDOW_SIZE / 2];
+
+    for window_index in 0..window_count {
+        let start = window_index * HOP_SIZE;
+        let mut buffer: Vec<Complex<f32>> = samples[start..start + WINDOW_SIZE]
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| {
+                // Hann window to reduce spectral leakage.
+                let hann = 0.5
+                    - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (WINDOW_SIZE - 1) as f32).cos();
+                Complex::new(s * hann, 0.0)
+            })
+            .collect();
+
+        fft.process(&mut buffer);
+
+        let magnitudes: Vec<f32> = buffer[..WINDOW_SIZE / 2].iter().map(|c| c.norm()).collect();
+
+        let window_flux: f32 = magnitudes
+            .iter()
+            .zip(prev_magnitudes.iter())
+            .map(|(curr, prev)| (curr - prev).max(0.0))
+            .sum();
+
+        flux.push(window_flux as f64);
+        prev_magnitudes = magnitudes;
+    }
+
+    peak_pick(&flux, sample_rate)
+}
+
+/// Picks local maxima in `flux` that exceed an adaptive local-mean threshold, converting
+/// window indices to millisecond timestamps.
+fn peak_pick(flux: &[f64], sample_rate: u32) -> Vec<OnsetMs> {
+    const LOCAL_WINDOW: usize = 10;
+
+    let mut onsets = Vec::new();
+    let hop_ms = (HOP_SIZE as f64 / sample_rate as f64) * 1000.0;
+
+    for i in 0..flux.len() {
+        let start = i.saturating_sub(LOCAL_WINDOW);
+        let end = (i + LOCAL_WINDOW + 1).min(flux.len());
+        let local_mean = flux[start..end].iter().sum::<f64>() / (end - start) as f64;
+
+        let is_peak = flux[i] > local_mean * ONSET_SENSITIVITY
+            && (i == 0 || flux[i] >= flux[i - 1])
+            && (i + 1 == flux.len() || flux[i] >= flux[i + 1]);
+
+        if is_peak && flux[i] > 0.0 {
+            onsets.push(i as f64 * hop_ms);
+        }
+    }
+
+    onsets
+}
+
+/// Estimates BPM from onset timestamps by histogramming quantized inter-onset
+/// intervals and folding the dominant interval into the `(MIN_BPM, MAX_BPM)` range.
+fn estimate_bpm(onsets: &[OnsetMs]) -> f64 {
+    use std::collections::HashMap;
+
+    if onsets.len() < 2 {
+        return 0.0;
+    }
+
+    let mut histogram: HashMap<i64, usize> = HashMap::new();
+
+    for pair in onsets.windows(2) {
+        let interval = pair[1] - pair[0];
+        if interval <= 0.0 {
+            continue;
+        }
+
+        let bucket = (interval / INTERVAL_BUCKET_MS).round() as i64;
+        *histogram.entry(bucket).or_default() += 1;
+    }
+
+    let dominant_interval_ms = histogram
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(bucket, _)| bucket as f64 * INTERVAL_BUCKET_MS)
+        .unwrap_or(0.0);
+
+    if dominant_interval_ms <= 0.0 {
+        return 0.0;
+    }
+
+    fold_into_range(60_000.0 / dominant_interval_ms)
+}
+
+/// Folds a raw BPM guess into the open range `(MIN_BPM, MAX_BPM)` by repeatedly
+/// halving/doubling, since the dominant inter-onset interval is often a multiple or
+/// fraction of the true beat.
+fn fold_into_range(mut bpm: f64) -> f64 {
+    while bpm > 0.0 && bpm <= MIN_BPM {
+        bpm *= 2.0;
+    }
+    while bpm >= MAX_BPM {
+        bpm /= 2.0;
+    }
+    bpm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fold_into_range_doubles_slow_bpm() {
+        assert_eq!(fold_into_range(30.0), 120.0);
+    }
+
+    #[test]
+    fn fold_into_range_halves_fast_bpm() {
+        assert_eq!(fold_into_range(600.0), 150.0);
+    }
+
+    #[test]
+    fn fold_into_range_leaves_in_range_bpm_untouched() {
+        assert_eq!(fold_into_range(140.0), 140.0);
+    }
+
+    #[test]
+    fn fold_into_range_leaves_non_positive_untouched() {
+        assert_eq!(fold_into_range(0.0), 0.0);
+    }
+
+    #[test]
+    fn estimate_bpm_of_too_few_onsets_is_zero() {
+        assert_eq!(estimate_bpm(&[]), 0.0);
+        assert_eq!(estimate_bpm(&[100.0]), 0.0);
+    }
+
+    #[test]
+    fn estimate_bpm_finds_dominant_interval() {
+        // Evenly spaced onsets 500ms apart => 120 BPM.
+        let onsets: Vec<f64> = (0..8).map(|i| i as f64 * 500.0).collect();
+
+        assert_eq!(estimate_bpm(&onsets), 120.0);
+    }
+
+    #[test]
+    fn estimate_bpm_folds_fast_interval_into_range() {
+        // 100ms apart (600 BPM) should fold down into range.
+        let onsets: Vec<f64> = (0..8).map(|i| i as f64 * 100.0).collect();
+
+        assert_eq!(estimate_bpm(&onsets), 150.0);
+    }
+}
@@ -1,7 +1,13 @@
 pub mod analyze;
+#[cfg(feature = "audio-analysis")]
+pub mod audio;
 mod utils;
 
 pub use rosu_map;
+pub use utils::{
+    active_bpm, bpm_segments, classify_snap, classify_snaps, snap_histogram, BpmSegment, Snap,
+    SnapHistogram,
+};
 
 #[cfg(test)]
 mod tests {
@@ -35,6 +35,64 @@ pub fn bpm(last_hit_object: Option<&mut HitObject>, timing_points: &[TimingPoint
     (60_000.0 / most_common_beat_len).max(1.0)
 }
 
+/// A stretch of the map with a constant BPM, derived from one timing point.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BpmSegment {
+    pub start_time: f64,
+    pub end_time: f64,
+    pub bpm: f64,
+}
+
+/// Returns the full ordered list of BPM segments derived from consecutive timing
+/// points, unlike `bpm` which collapses the whole map to a single dominant value.
+/// Useful for marathon maps with genuine tempo changes.
+pub fn bpm_segments(
+    last_hit_object: Option<&mut HitObject>,
+    timing_points: &[TimingPoint],
+) -> Vec<BpmSegment> {
+    let last_time = last_hit_object
+        .map(HitObject::end_time)
+        .or_else(|| timing_points.last().map(|t| t.time))
+        .unwrap_or(0.0);
+
+    timing_points
+        .iter()
+        .enumerate()
+        .filter_map(|(i, curr)| {
+            let end_time = timing_points.get(i + 1).map_or(last_time, |next| next.time);
+
+            if end_time <= curr.time {
+                return None;
+            }
+
+            Some(BpmSegment {
+                start_time: curr.time,
+                end_time,
+                bpm: (60_000.0 / curr.beat_len).max(1.0),
+            })
+        })
+        .collect()
+}
+
+/// Returns the BPM active at `time` according to `segments`: the segment containing
+/// `time`, or the nearest segment if `time` falls before the first or after the last.
+/// Falls back to `fallback_bpm` if `segments` is empty.
+pub fn active_bpm(segments: &[BpmSegment], time: f64, fallback_bpm: f64) -> f64 {
+    if let Some(segment) = segments
+        .iter()
+        .find(|segment| time >= segment.start_time && time < segment.end_time)
+    {
+        return segment.bpm;
+    }
+
+    match segments {
+        [] => fallback_bpm,
+        [first, ..] if time < first.start_time => first.bpm,
+        [.., last] => last.bpm,
+    }
+}
+
 struct BeatLenDuration {
     last_time: f64,
     map: HashMap<u64, f64>,
@@ -57,3 +115,155 @@ impl BeatLenDuration {
         }
     }
 }
+
+/// A musical subdivision that an inter-note gap snaps to, relative to the beat length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum Snap {
+    /// 1/1th
+    Whole,
+    /// 1/2th
+    Half,
+    /// 1/3th
+    Third,
+    /// 1/4th
+    Quarter,
+    /// 1/6th
+    Sixth,
+    /// 1/8th
+    Eighth,
+    /// Doesn't fit any of the above within tolerance.
+    Irregular,
+}
+
+/// Subdivisions tried when classifying a gap, paired with their divisor of `beat_length`.
+const SUBDIVISIONS: [(f64, Snap); 6] = [
+    (1.0, Snap::Whole),
+    (2.0, Snap::Half),
+    (3.0, Snap::Third),
+    (4.0, Snap::Quarter),
+    (6.0, Snap::Sixth),
+    (8.0, Snap::Eighth),
+];
+
+/// Maximum allowed relative error between `gap` and the nearest subdivision before
+/// it's classified as `Snap::Irregular`.
+const SNAP_TOLERANCE: f64 = 0.10;
+
+/// Classifies an inter-note `gap` as the nearest musical subdivision of `beat_length`,
+/// out of {1/1, 1/2, 1/3, 1/4, 1/6, 1/8}, or `Snap::Irregular` if none are close enough.
+pub fn classify_snap(gap: f64, beat_length: f64) -> Snap {
+    if beat_length <= 0.0 || gap <= 0.0 {
+        return Snap::Irregular;
+    }
+
+    let (snap, error, expected) = SUBDIVISIONS
+        .iter()
+        .map(|&(divisor, snap)| {
+            let expected = beat_length / divisor;
+            (snap, (gap - expected).abs(), expected)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+
+    if error / expected <= SNAP_TOLERANCE {
+        snap
+    } else {
+        Snap::Irregular
+    }
+}
+
+/// Classifies every consecutive pair of `times` (e.g. hit object start times) against
+/// `beat_length`, returning one `Snap` per gap.
+pub fn classify_snaps(times: &[f64], beat_length: f64) -> Vec<Snap> {
+    times
+        .windows(2)
+        .map(|pair| classify_snap(pair[1] - pair[0], beat_length))
+        .collect()
+}
+
+/// A histogram of `Snap` counts, as produced by `snap_histogram`.
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct SnapHistogram {
+    pub whole: usize,
+    pub half: usize,
+    pub third: usize,
+    pub quarter: usize,
+    pub sixth: usize,
+    pub eighth: usize,
+    pub irregular: usize,
+}
+
+/// Tallies `snaps` into a `SnapHistogram`.
+pub fn snap_histogram(snaps: &[Snap]) -> SnapHistogram {
+    let mut histogram = SnapHistogram::default();
+
+    for snap in snaps {
+        match snap {
+            Snap::Whole => histogram.whole += 1,
+            Snap::Half => histogram.half += 1,
+            Snap::Third => histogram.third += 1,
+            Snap::Quarter => histogram.quarter += 1,
+            Snap::Sixth => histogram.sixth += 1,
+            Snap::Eighth => histogram.eighth += 1,
+            Snap::Irregular => histogram.irregular += 1,
+        }
+    }
+
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_snap_recognizes_halves_and_quarters() {
+        let beat_length = 500.0;
+
+        assert_eq!(classify_snap(250.0, beat_length), Snap::Half);
+        assert_eq!(classify_snap(125.0, beat_length), Snap::Quarter);
+        assert_eq!(classify_snap(500.0, beat_length), Snap::Whole);
+    }
+
+    #[test]
+    fn classify_snap_recognizes_triplets_and_sextuplets() {
+        let beat_length = 600.0;
+
+        assert_eq!(classify_snap(200.0, beat_length), Snap::Third);
+        assert_eq!(classify_snap(100.0, beat_length), Snap::Sixth);
+    }
+
+    #[test]
+    fn classify_snap_rejects_out_of_tolerance_gaps() {
+        let beat_length = 500.0;
+
+        assert_eq!(classify_snap(300.0, beat_length), Snap::Irregular);
+    }
+
+    #[test]
+    fn classify_snap_rejects_non_positive_input() {
+        assert_eq!(classify_snap(0.0, 500.0), Snap::Irregular);
+        assert_eq!(classify_snap(250.0, 0.0), Snap::Irregular);
+        assert_eq!(classify_snap(-10.0, 500.0), Snap::Irregular);
+    }
+
+    #[test]
+    fn classify_snaps_maps_each_consecutive_gap() {
+        let times = [0.0, 250.0, 500.0];
+        let snaps = classify_snaps(&times, 500.0);
+
+        assert_eq!(snaps, vec![Snap::Half, Snap::Half]);
+    }
+
+    #[test]
+    fn snap_histogram_tallies_each_variant() {
+        let histogram = snap_histogram(&[Snap::Half, Snap::Half, Snap::Quarter, Snap::Irregular]);
+
+        assert_eq!(histogram.half, 2);
+        assert_eq!(histogram.quarter, 1);
+        assert_eq!(histogram.irregular, 1);
+        assert_eq!(histogram.whole, 0);
+    }
+}